@@ -16,8 +16,11 @@
  */
 
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
 use std::str::FromStr;
 
+use inertia_core::flint_sys::{fmpz, ulong_extras};
 use inertia_core as core;
 use inertia_core::{impl_unop, impl_from, impl_binop};
 
@@ -25,7 +28,7 @@ use inertia_algebra::*;
 use inertia_algebra::ops::*;
 use inertia_generic::mat::GenericMatSpace;
 
-use crate::{IntPolyRing, IntoPolyRing, IntoMatSpace};
+use crate::{IntPolyRing, IntoPolyRing, IntoMatSpace, GcdDomain};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -82,6 +85,18 @@ impl IntoMatSpace for Integers {
     type Inner = GenericMatSpace<Self>;
 }
 
+impl GcdDomain for Integers {
+    #[inline]
+    fn gcd(&self, a: &Integer, b: &Integer) -> Integer {
+        a.gcd(b)
+    }
+
+    #[inline]
+    fn divexact(&self, a: &Integer, b: &Integer) -> Integer {
+        a.divexact(b)
+    }
+}
+
 impl Integers {
     #[inline]
     pub fn init() -> Self {
@@ -93,10 +108,41 @@ impl Integers {
 // Integer
 ///////////////////////////////////////////////////////////////////////
 
-#[derive(Clone, Default, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Integer(pub(crate) core::Integer);
 
+// Hash the value rather than the raw fmpz, which for large values is a pointer
+// to an mpz, so equal integers always hash equally. Whether a value fits in an
+// i64 depends only on the value, so the two cases never mix: small values hash
+// as an i64, larger ones as their sign followed by the limbs of the absolute
+// value.
+impl Hash for Integer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // SAFETY: `self` is an initialized fmpz for the duration of the calls.
+        if unsafe { fmpz::fmpz_fits_si(self.as_ptr()) } != 0 {
+            unsafe { fmpz::fmpz_get_si(self.as_ptr()) }.hash(state);
+            return;
+        }
+        
+        // SAFETY: `fmpz_get_ui_array` needs a non-negative input and room for
+        // `fmpz_size` limbs, both of which hold for `abs` and `limbs`.
+        unsafe {
+            let sign = self.sign();
+            let n = fmpz::fmpz_size(self.as_ptr()) as usize;
+            let mut limbs = vec![0; n];
+            if sign < 0 {
+                let abs = self.abs();
+                fmpz::fmpz_get_ui_array(limbs.as_mut_ptr(), n as _, abs.as_ptr());
+            } else {
+                fmpz::fmpz_get_ui_array(limbs.as_mut_ptr(), n as _, self.as_ptr());
+            }
+            sign.hash(state);
+            limbs.hash(state);
+        }
+    }
+}
+
 impl AsRef<Integer> for Integer {
     #[inline]
     fn as_ref(&self) -> &Integer {
@@ -186,6 +232,20 @@ impl Integer {
         &mut self.0
     }
 
+    /// A pointer to the underlying `fmpz` for passing to FLINT. It is only
+    /// valid while `self` is borrowed.
+    #[inline]
+    pub fn as_ptr(&self) -> *const fmpz::fmpz {
+        self.0.as_ptr()
+    }
+    
+    /// A mutable pointer to the underlying `fmpz` for passing to FLINT. It is
+    /// only valid while `self` is mutably borrowed.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut fmpz::fmpz {
+        self.0.as_mut_ptr()
+    }
+
     #[inline]
     pub fn to_str_radix(&self, base: u8) -> String {
         core::Integer::to_str_radix(&self.0, base)
@@ -195,6 +255,242 @@ impl Integer {
     pub fn from_raw(inner: core::Integer) -> Self {
         Integer(inner)
     }
+
+    /// Swap the values of `self` and `other` without reallocating.
+    #[inline]
+    pub fn swap(&mut self, other: &mut Integer) {
+        // SAFETY: two distinct `&mut` borrows, so the pointers cannot alias.
+        unsafe { fmpz::fmpz_swap(self.as_mut_ptr(), other.as_mut_ptr()); }
+    }
+
+    #[inline]
+    pub fn set_zero(&mut self) {
+        // SAFETY: `self` is an initialized fmpz and we hold the only borrow.
+        unsafe { fmpz::fmpz_zero(self.as_mut_ptr()); }
+    }
+    
+    #[inline]
+    pub fn set_one(&mut self) {
+        // SAFETY: `self` is an initialized fmpz and we hold the only borrow.
+        unsafe { fmpz::fmpz_one(self.as_mut_ptr()); }
+    }
+
+    #[inline]
+    pub fn abs(&self) -> Integer {
+        let mut res = Integer::zero();
+        // SAFETY: `res` is a fresh initialized fmpz distinct from `self`.
+        unsafe { fmpz::fmpz_abs(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+    
+    /// The sign of the integer: -1, 0 or 1.
+    #[inline]
+    pub fn sign(&self) -> i32 {
+        // SAFETY: read-only query on an initialized fmpz.
+        unsafe { fmpz::fmpz_sgn(self.as_ptr()) as i32 }
+    }
+
+    /// The non-negative gcd of `self` and `other`.
+    #[inline]
+    pub fn gcd(&self, other: &Integer) -> Integer {
+        Integer(self.0.gcd(&other.0))
+    }
+
+    /// The extended gcd `(g, s, t)` with `g = s*self + t*other`, where the 
+    /// Bezout coefficients are the unique canonical ones, e.g. `(7, 1, -1)` 
+    /// for `self = 21` and `other = 14`.
+    #[inline]
+    pub fn xgcd_canonical(&self, other: &Integer) -> (Integer, Integer, Integer) {
+        let mut g = Integer::zero();
+        let mut s = Integer::zero();
+        let mut t = Integer::zero();
+        // SAFETY: the outputs are fresh initialized fmpz values, distinct from
+        // each other and from the inputs.
+        unsafe {
+            fmpz::fmpz_xgcd_canonical_bezout(
+                g.as_mut_ptr(),
+                s.as_mut_ptr(),
+                t.as_mut_ptr(),
+                self.as_ptr(),
+                other.as_ptr()
+            );
+        }
+        (g, s, t)
+    }
+
+    /// Exact division. The result is undefined if `other` does not divide
+    /// `self`.
+    #[inline]
+    pub fn divexact(&self, other: &Integer) -> Integer {
+        assert!(!other.is_zero());
+        let mut res = Integer::zero();
+        // SAFETY: `res` is a fresh fmpz and `other` was checked to be nonzero.
+        unsafe { fmpz::fmpz_divexact(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); }
+        res
+    }
+
+    /// The value as a `u64`, or `None` if it is negative or too large.
+    #[inline]
+    pub fn get_ui(&self) -> Option<u64> {
+        // SAFETY: read-only queries on an initialized fmpz. `fmpz_get_ui` is
+        // only reached once the value is known to fit.
+        unsafe {
+            if self.sign() >= 0 && fmpz::fmpz_abs_fits_ui(self.as_ptr()) != 0 {
+                Some(fmpz::fmpz_get_ui(self.as_ptr()) as u64)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Whether the integer is prime. The result is proven, not probabilistic.
+    #[inline]
+    pub fn is_prime(&self) -> bool {
+        // SAFETY: read-only query on an initialized fmpz.
+        unsafe { fmpz::fmpz_is_prime(self.as_ptr()) == 1 }
+    }
+
+    /// The Jacobi symbol `(self/n)` for odd positive `n`.
+    #[inline]
+    pub fn jacobi(&self, n: &Integer) -> i32 {
+        // SAFETY: read-only queries, and `fmpz_jacobi` requires the odd 
+        // positive modulus asserted first.
+        unsafe {
+            assert!(n.sign() > 0 && fmpz::fmpz_is_odd(n.as_ptr()) != 0,
+                "modulus must be odd and positive");
+            fmpz::fmpz_jacobi(self.as_ptr(), n.as_ptr()) as i32
+        }
+    }
+
+    /// The Legendre symbol `(self/p)` for an odd prime `p`: 1 if `self` is a
+    /// nonzero quadratic residue mod `p`, -1 if it is a non-residue and 0 if 
+    /// `p` divides `self`. Panics if `p` is not an odd prime.
+    #[inline]
+    pub fn legendre(&self, p: &Integer) -> i32 {
+        // SAFETY: read-only query on an initialized fmpz.
+        assert!(p.is_prime() && unsafe { fmpz::fmpz_is_odd(p.as_ptr()) != 0 }, 
+            "modulus must be an odd prime");
+        self.jacobi(p)
+    }
+
+    /// The distinct nonzero quadratic residues modulo the prime `p` in 
+    /// increasing order, computed by squaring `1, ..., (p-1)/2`.
+    pub fn quadratic_residues_mod(p: &Integer) -> Vec<Integer> {
+        let n = p.get_ui().expect("modulus does not fit in a u64");
+        assert!(p.is_prime(), "modulus must be prime");
+        let half = if n == 2 { 1 } else { (n - 1) / 2 };
+        let mut res: Vec<u64> = (1..=half)
+            .map(|i| ((i as u128 * i as u128) % n as u128) as u64)
+            .collect();
+        res.sort_unstable();
+        res.into_iter().map(Integer::from).collect()
+    }
+
+    /// Modular exponentiation `self^exp mod modulus` with a word-sized 
+    /// exponent and modulus given as any primitive integers, e.g. 
+    /// `powm_uu(10, 1000)`. The result is in `[0, modulus)`. Panics if either
+    /// argument is negative.
+    pub fn powm_uu<S1, S2>(&self, exp: S1, modulus: S2) -> Integer
+    where
+        S1: TryInto<u64>,
+        S2: TryInto<u64>
+    {
+        let exp = exp.try_into().ok().expect("exponent must be non-negative");
+        let n = modulus.try_into().ok().expect("modulus must be non-negative");
+        assert!(n != 0, "division by zero");
+        if n == 1 {
+            return Integer::zero();
+        }
+        // SAFETY: `n` is nonzero, and `a < n` as `n_powmod2_ui_preinv` needs.
+        unsafe {
+            let a = fmpz::fmpz_fdiv_ui(self.as_ptr(), n);
+            let ninv = ulong_extras::n_preinvert_limb(n);
+            Integer::from(ulong_extras::n_powmod2_ui_preinv(a, exp, n, ninv) as u64)
+        }
+    }
+
+    /// Whether `self` is a primitive root modulo the positive word-sized
+    /// `modulus`, i.e. its residue generates the unit group of `Z/nZ`.
+    pub fn is_primitive_root(&self, modulus: &Integer) -> bool {
+        let n = modulus.get_ui().expect("modulus does not fit in a u64");
+        assert!(n > 0, "modulus must be positive");
+        // SAFETY: `n` was checked to be nonzero.
+        let g = unsafe { fmpz::fmpz_fdiv_ui(self.as_ptr(), n) };
+        UnitGroupOrder::new(n).is_generator(g)
+    }
+
+    /// The smallest positive primitive root modulo `self`, or `None` if there 
+    /// is none. Primitive roots exist exactly for the moduli 1, 2, 4, `p^k` and
+    /// `2p^k` with `p` an odd prime. The modulus must fit in a `u64`.
+    pub fn primitive_root(&self) -> Option<Integer> {
+        let n = self.get_ui().expect("modulus does not fit in a u64");
+        assert!(n > 0, "modulus must be positive");
+        // The unit group is trivial for n = 1, so 1 generates it.
+        if n <= 4 {
+            return Some(Integer::from((n - 1).max(1)));
+        }
+        
+        let odd = if n % 2 == 0 { n / 2 } else { n };
+        if odd % 2 == 0 || prime_factors_ui(odd).len() != 1 {
+            return None;
+        }
+        let order = UnitGroupOrder::new(n);
+        (2..n).find(|&g| order.is_generator(g)).map(Integer::from)
+    }
+
+    /// The number of bits in the absolute value, zero for zero.
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        // SAFETY: read-only query on an initialized fmpz.
+        unsafe { fmpz::fmpz_bits(self.as_ptr()) as u64 }
+    }
+
+    /// Raise to the power `e`, returning `None` without computing anything if
+    /// the result could need more than `max_bits` bits. The check uses the
+    /// estimate `self.bits() * e`, which can overshoot the true size by about
+    /// a factor of two. Results needing only about half the budget may
+    /// therefore be rejected: `2.checked_pow(1000, 1500)` is `None` even
+    /// though `2^1000` has 1001 bits.
+    pub fn checked_pow(&self, e: u64, max_bits: u64) -> Option<Integer> {
+        // SAFETY: read-only queries on an initialized fmpz.
+        let trivial = unsafe { fmpz::fmpz_is_zero(self.as_ptr()) != 0 
+            || fmpz::fmpz_is_pm1(self.as_ptr()) != 0 };
+        if !trivial && self.bits().checked_mul(e)? > max_bits {
+            return None;
+        }
+        let mut res = Integer::zero();
+        // SAFETY: `res` is a fresh initialized fmpz distinct from `self`.
+        unsafe { fmpz::fmpz_pow_ui(res.as_mut_ptr(), self.as_ptr(), e); }
+        Some(res)
+    }
+
+    /// Modular exponentiation `self^e mod m` for arbitrary `e`, with the
+    /// result in `[0, m)`. Its size is bounded by the modulus, so unlike
+    /// `checked_pow` no bit budget is needed. A negative exponent raises the
+    /// inverse of `self`. Returns `None` if `m` is not positive, or if `e` is 
+    /// negative and `self` is not invertible modulo `m`.
+    pub fn pow_mod_checked(&self, e: &Integer, m: &Integer) -> Option<Integer> {
+        if m.sign() <= 0 {
+            return None;
+        }
+        let mut base = Integer::zero();
+        let mut exp = e.clone();
+        // SAFETY: `m` is positive as `fmpz_invmod` and `fmpz_powm` need, and 
+        // the outputs are distinct from the inputs except for the in-place 
+        // negation and power, which FLINT allows.
+        unsafe {
+            if e.sign() < 0 {
+                if fmpz::fmpz_invmod(base.as_mut_ptr(), self.as_ptr(), m.as_ptr()) == 0 {
+                    return None;
+                }
+                fmpz::fmpz_neg(exp.as_mut_ptr(), exp.as_ptr());
+            } else {
+                fmpz::fmpz_set(base.as_mut_ptr(), self.as_ptr());
+            }
+            fmpz::fmpz_powm(base.as_mut_ptr(), base.as_ptr(), exp.as_ptr(), m.as_ptr());
+        }
+        Some(base)
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////
@@ -279,12 +575,392 @@ derive_binop! {
     AssignRem, assign_rem
 }
 
-/*
+// Arithmetic with a primitive integer on the right, delegated to inertia-core
+// so no temporary Integer is built for the primitive.
 derive_binop! {
-    Integer, {u64}, Integer
+    None
+    op_assign
+    Integer, {usize u8 u16 u32 u64 isize i8 i16 i32 i64}, Integer
 
     Add, add
     AddAssign, add_assign
     AssignAdd, assign_add
+
+    Sub, sub
+    SubAssign, sub_assign
+    AssignSub, assign_sub
+
+    Mul, mul
+    MulAssign, mul_assign
+    AssignMul, assign_mul
+}
+
+// Remainder by a primitive integer, delegated to inertia-core so it rounds the
+// same way as `Integer % Integer` and `z % 5 == z % Integer::from(5)`. Panics
+// if the divisor is zero.
+derive_binop! {
+    None
+    op_assign
+    Integer, {usize u8 u16 u32 u64 isize i8 i16 i32 i64}, Integer
+
+    Rem, rem
+    RemAssign, rem_assign
+    AssignRem, assign_rem
+}
+
+// Exponents are converted to a u64, panicking if negative or too large. A
+// negative power only exists modulo some m, see `Integer::pow_mod_checked`.
+impl Pow<&Integer> for &Integer {
+    type Output = Integer;
+    #[inline]
+    fn pow(self, exp: &Integer) -> Integer {
+        let e = exp.get_ui().expect(
+            "exponent is negative or does not fit in a u64, \
+            use pow_mod_checked for negative powers modulo m"
+        );
+        let mut res = Integer::zero();
+        // SAFETY: `res` is a fresh initialized fmpz distinct from `self`.
+        unsafe { fmpz::fmpz_pow_ui(res.as_mut_ptr(), self.as_ptr(), e); }
+        res
+    }
+}
+
+impl Pow<&Integer> for Integer {
+    type Output = Integer;
+    #[inline]
+    fn pow(self, exp: &Integer) -> Integer {
+        (&self).pow(exp)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+// Functions
+///////////////////////////////////////////////////////////////////////
+
+// The distinct prime factors of a word-sized integer.
+fn prime_factors_ui(n: u64) -> Vec<u64> {
+    // SAFETY: `n_factor_t` is plain integer arrays, so the all-zero value is
+    // a valid initial state, and `n_factor` only fills the first `num` slots.
+    unsafe {
+        let mut fac: ulong_extras::n_factor_t = MaybeUninit::zeroed().assume_init();
+        ulong_extras::n_factor_init(&mut fac);
+        ulong_extras::n_factor(&mut fac, n, 1);
+        fac.p[..fac.num as usize].iter().map(|&p| p as u64).collect()
+    }
+}
+
+// The data needed to test elements of (Z/nZ)^* for having the full order 
+// phi(n), computed once per modulus.
+struct UnitGroupOrder {
+    n: u64,
+    ninv: u64,
+    phi: u64,
+    phi_factors: Vec<u64>
+}
+
+impl UnitGroupOrder {
+    fn new(n: u64) -> Self {
+        assert!(n > 0);
+        // SAFETY: word arithmetic, and `n_preinvert_limb` only needs `n > 0`.
+        let (phi, ninv) = unsafe { 
+            (ulong_extras::n_euler_phi(n), ulong_extras::n_preinvert_limb(n)) 
+        };
+        UnitGroupOrder { n, ninv, phi, phi_factors: prime_factors_ui(phi) }
+    }
+
+    // Whether `g < n` has order phi(n), i.e. it is a unit and 
+    // g^(phi(n)/q) != 1 for every prime q dividing phi(n).
+    fn is_generator(&self, g: u64) -> bool {
+        if self.n == 1 {
+            return true;
+        }
+        // SAFETY: word arithmetic with `n > 1` and `g < n`.
+        unsafe {
+            ulong_extras::n_gcd(g, self.n) == 1 && self.phi_factors.iter().all(|&q| {
+                ulong_extras::n_powmod2_ui_preinv(g, self.phi / q, self.n, self.ninv) != 1
+            })
+        }
+    }
+}
+
+/// A gcd-free basis of `xs`: a list of pairwise coprime integers greater than
+/// one such that every nonzero input is, up to sign, a product of powers of
+/// its elements.
+pub fn coprime_base(xs: &[Integer]) -> Vec<Integer> {
+    let mut base: Vec<Integer> = Vec::new();
+    for x in xs {
+        let mut todo = vec![x.abs()];
+        while let Some(a) = todo.pop() {
+            if a.is_zero() || a.is_one() {
+                continue;
+            }
+            // Replace any b sharing a factor g with a by g, a/g, b/g and 
+            // refine those instead.
+            match base.iter().position(|b| !a.gcd(b).is_one()) {
+                Some(i) => {
+                    let b = base.swap_remove(i);
+                    let g = a.gcd(&b);
+                    todo.push(a.divexact(&g));
+                    todo.push(b.divexact(&g));
+                    todo.push(g);
+                },
+                None => base.push(a)
+            }
+        }
+    }
+    base
+}
+
+// FLINT answers `nth_prime` and `prime_pi` from a table of all primes up to
+// the argument, cached per thread in `_flint_primes` at about 16 bytes per
+// prime until `runtime::cleanup()` is called. The caps keep that table to
+// roughly a million primes, about 17 MB.
+const NTH_PRIME_MAX: u64 = 1 << 20;
+const PRIME_PI_MAX: u64 = 1 << 24;
+
+/// The `n`-th prime, counting from `nth_prime(1) == 2`. Panics unless 
+/// `1 <= n <= 2^20`. The primes up to the result stay cached by FLINT for the
+/// calling thread, at about 16 bytes each, until [runtime::cleanup] is 
+/// called.
+///
+/// [runtime::cleanup]: crate::runtime::cleanup
+#[inline]
+pub fn nth_prime(n: u64) -> Integer {
+    assert!(n > 0, "primes are indexed from 1");
+    assert!(n <= NTH_PRIME_MAX, "nth_prime is only supported for indices up to 2^20");
+    // SAFETY: `n_nth_prime` only requires `n > 0`.
+    Integer::from(unsafe { ulong_extras::n_nth_prime(n) } as u64)
+}
+
+/// The number of primes less than or equal to `x`. Returns zero for negative
+/// `x` and panics for `x` above `2^24`. The primes up to `x` stay cached by
+/// FLINT for the calling thread, at about 16 bytes each, until 
+/// [runtime::cleanup] is called.
+///
+/// [runtime::cleanup]: crate::runtime::cleanup
+#[inline]
+pub fn prime_pi(x: &Integer) -> Integer {
+    if x.sign() < 0 {
+        return Integer::zero();
+    }
+    let n = x.get_ui()
+        .filter(|&n| n <= PRIME_PI_MAX)
+        .expect("prime_pi is only supported for arguments up to 2^24");
+    // SAFETY: word arithmetic with no preconditions.
+    Integer::from(unsafe { ulong_extras::n_prime_pi(n) } as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    #[test]
+    fn hash_by_value() {
+        let max = Integer::from(u64::MAX);
+        let big = "340282366920938463426481119284349108225";
+        for (a, b) in [
+            (&max * &max, Integer::from_str(big).unwrap()),
+            (-(&max * &max), Integer::from_str(&format!("-{}", big)).unwrap()),
+            (Integer::from(-5) + Integer::from(12), Integer::from_str("7").unwrap())
+        ] {
+            let mut map = HashMap::new();
+            map.insert(a, 1);
+            *map.entry(b).or_insert(0) += 1;
+            assert_eq!(map.len(), 1);
+            assert_eq!(map.values().next(), Some(&2));
+        }
+    }
+
+    #[test]
+    fn primitive_root() {
+        assert!(Integer::from(3).is_primitive_root(&Integer::from(7)));
+        assert!(!Integer::from(2).is_primitive_root(&Integer::from(7)));
+        assert_eq!(Integer::from(7).primitive_root(), Some(Integer::from(3)));
+        assert_eq!(Integer::from(8).primitive_root(), None);
+    }
+
+    #[test]
+    fn powm_uu() {
+        assert_eq!(Integer::from(2).powm_uu(10, 1000), Integer::from(24));
+        assert_eq!(Integer::from(-2).powm_uu(3u8, 7u64), Integer::from(6));
+        assert_eq!(Integer::from(5).powm_uu(0, 1), Integer::zero());
+    }
+
+    #[test]
+    fn coprime_base_12_18() {
+        let xs = [Integer::from(12), Integer::from(18)];
+        let base = coprime_base(&xs);
+        assert_eq!(base.len(), 2);
+        assert!(base.contains(&Integer::from(2)) && base.contains(&Integer::from(3)));
+        
+        for (i, a) in base.iter().enumerate() {
+            for b in &base[i + 1..] {
+                assert!(a.gcd(b).is_one());
+            }
+        }
+        for x in &xs {
+            let mut rest = x.clone();
+            for b in &base {
+                while (&rest % b).is_zero() {
+                    rest = rest.divexact(b);
+                }
+            }
+            assert!(rest.is_one());
+        }
+    }
+
+    #[test]
+    fn legendre_mod_7() {
+        let p = Integer::from(7);
+        assert_eq!(Integer::from(2).legendre(&p), 1);
+        assert_eq!(Integer::from(3).legendre(&p), -1);
+        assert_eq!(Integer::from(14).legendre(&p), 0);
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut z = Integer::from(10);
+        z += Integer::from(5);
+        z += &Integer::from(5);
+        z += 5i64;
+        assert_eq!(z, Integer::from(25));
+        
+        z -= Integer::from(3);
+        z -= &Integer::from(2);
+        z -= 4i64;
+        assert_eq!(z, Integer::from(16));
+        
+        z *= Integer::from(2);
+        z *= &Integer::from(3);
+        z *= -1i64;
+        assert_eq!(z, Integer::from(-96));
+    }
+
+    #[test]
+    fn rem_primitive() {
+        for z in [17i64, -17, 15, -15] {
+            let z = Integer::from(z);
+            assert_eq!(&z % 5u64, &z % Integer::from(5));
+            assert_eq!(&z % -5i32, &z % Integer::from(-5));
+            let mut w = z.clone();
+            w %= 5u8;
+            assert_eq!(w, &z % Integer::from(5));
+        }
+    }
+
+    #[test]
+    fn checked_pow() {
+        let two = Integer::from(2);
+        assert_eq!(two.checked_pow(1000, 1500), None);
+        assert_eq!(two.checked_pow(u64::MAX, 1 << 20), None);
+        assert_eq!(two.checked_pow(10, 64), Some(Integer::from(1024)));
+        assert_eq!(Integer::from(-1).checked_pow(u64::MAX, 0), Some(Integer::from(-1)));
+    }
+
+    #[test]
+    fn pow_mod_checked() {
+        let m = Integer::from(7);
+        let a = Integer::from(3);
+        assert_eq!(a.pow_mod_checked(&Integer::from(4), &m), Some(Integer::from(4)));
+        assert_eq!(a.pow_mod_checked(&Integer::from(-1), &m), Some(Integer::from(5)));
+        assert_eq!(Integer::from(14).pow_mod_checked(&Integer::from(-1), &m), None);
+        assert_eq!(a.pow_mod_checked(&Integer::from(4), &Integer::zero()), None);
+    }
+
+    #[test]
+    fn quadratic_residues_mod() {
+        let residues = |p: i64| Integer::quadratic_residues_mod(&Integer::from(p));
+        assert_eq!(residues(7), [1, 2, 4].map(Integer::from).to_vec());
+        assert_eq!(residues(2), vec![Integer::one()]);
+        assert_eq!(residues(13), [1, 3, 4, 9, 10, 12].map(Integer::from).to_vec());
+        for r in residues(13) {
+            assert_eq!(r.legendre(&Integer::from(13)), 1);
+        }
+    }
+
+    #[test]
+    fn xgcd_canonical() {
+        let (a, b) = (Integer::from(21), Integer::from(14));
+        assert_eq!(
+            a.xgcd_canonical(&b), 
+            (Integer::from(7), Integer::from(1), Integer::from(-1))
+        );
+        
+        for (a, b) in [(240, 46), (-35, 15), (17, 0), (0, -4)] {
+            let (a, b) = (Integer::from(a), Integer::from(b));
+            let (g, s, t) = a.xgcd_canonical(&b);
+            assert_eq!(g, a.gcd(&b));
+            assert_eq!(&s * &a + &t * &b, g);
+        }
+    }
+
+    #[test]
+    fn pow_integer() {
+        let two = Integer::from(2);
+        assert_eq!((&two).pow(&Integer::from(10)), Integer::from(1024));
+        assert_eq!(Integer::from(-3).pow(&Integer::from(3)), Integer::from(-27));
+        assert!(Integer::from(7).pow(&Integer::zero()).is_one());
+    }
+
+    #[test]
+    #[should_panic(expected = "use pow_mod_checked")]
+    fn pow_negative_integer() {
+        Integer::from(2).pow(&Integer::from(-1));
+    }
+
+    #[test]
+    fn nth_prime_and_prime_pi() {
+        assert_eq!(nth_prime(1), Integer::from(2));
+        assert_eq!(nth_prime(6), Integer::from(13));
+        assert_eq!(prime_pi(&Integer::from(10)), Integer::from(4));
+        assert_eq!(prime_pi(&Integer::from(13)), Integer::from(6));
+        assert!(prime_pi(&Integer::from(-10)).is_zero());
+        runtime::cleanup();
+    }
+
+    #[test]
+    #[should_panic(expected = "up to 2^24")]
+    fn prime_pi_too_large() {
+        prime_pi(&Integer::from(1u64 << 32));
+    }
+
+    #[test]
+    fn primitive_root_small_moduli() {
+        let root = |n: u64| Integer::from(n).primitive_root();
+        assert_eq!(root(1), Some(Integer::one()));
+        assert_eq!(root(2), Some(Integer::one()));
+        assert_eq!(root(3), Some(Integer::from(2)));
+        assert_eq!(root(4), Some(Integer::from(3)));
+        assert_eq!(root(9), Some(Integer::from(2)));
+        assert_eq!(root(12), None);
+    }
+
+    #[test]
+    fn swap_set_zero_set_one() {
+        let big = Integer::from(u64::MAX) * Integer::from(u64::MAX);
+        let mut a = big.clone();
+        let mut b = Integer::from(-7);
+        a.swap(&mut b);
+        assert_eq!(a, Integer::from(-7));
+        assert_eq!(b, big);
+        
+        b.set_zero();
+        assert!(b.is_zero());
+        a.set_one();
+        assert!(a.is_one());
+        b.set_one();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn primitive_ops() {
+        let z = Integer::from(-12345);
+        assert_eq!(&z + u64::MAX, &z + Integer::from(u64::MAX));
+        assert_eq!(&z - i64::MIN, &z - Integer::from(i64::MIN));
+        assert_eq!(&z * 7u8, Integer::from(-86415));
+        assert_eq!(&z * -2isize, Integer::from(24690));
+        assert_eq!(z.clone() + 345u16 - 1000i32, Integer::from(-13000));
+    }
 }
-*/
@@ -17,10 +17,14 @@
 
 use std::fmt;
 use std::hash::{Hash, Hasher};
-//use std::str::FromStr;
+use std::str::FromStr;
 use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+use std::os::raw::{c_long, c_void};
 use std::rc::Rc;
 
+use inertia_core::flint_sys::{flint, fmpz, fmpz_vec, fmpz_poly, nmod_poly};
 use inertia_core as core;
 use inertia_core::{impl_unop, impl_from, impl_binop};
 
@@ -29,7 +33,7 @@ use inertia_algebra::ops::*;
 use inertia_generic::poly::GenericPolyRing;
 use inertia_generic::mat::GenericMatSpace;
 
-use crate::{Integer, Integers, IntoPolyRing, IntoMatSpace};
+use crate::{Integer, Integers, IntoPolyRing, IntoMatSpace, GcdDomain, PolynomialRingElementExt};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -176,6 +180,18 @@ impl IntoMatSpace for IntPolyRing {
     type Inner = GenericMatSpace<Self>;
 }
 
+impl GcdDomain for IntPolyRing {
+    #[inline]
+    fn gcd(&self, a: &IntPoly, b: &IntPoly) -> IntPoly {
+        a.gcd(b)
+    }
+
+    #[inline]
+    fn divexact(&self, a: &IntPoly, b: &IntPoly) -> IntPoly {
+        a.try_div(b).expect("inexact polynomial division")
+    }
+}
+
 impl IntPolyRing {
     pub fn context(&self) -> &IntPolyCtx {
         &self.ctx
@@ -200,10 +216,45 @@ impl AsRef<IntPoly> for IntPoly {
     }
 }
 
+// Print e.g. `-x^3 + 2*x - 1` in the variable of the polynomial. The zero
+// polynomial prints as `0` and constants without the variable.
 impl fmt::Display for IntPoly {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.inner().fmt(f)
+        let var = self.var();
+        let mut first = true;
+        for (i, c) in self.get_coefficients().iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            
+            let neg = c.sign() < 0;
+            if first {
+                if neg {
+                    write!(f, "-")?;
+                }
+            } else {
+                write!(f, " {} ", if neg { '-' } else { '+' })?;
+            }
+            first = false;
+            
+            let c = c.abs();
+            if i == 0 {
+                write!(f, "{}", c)?;
+            } else if !c.is_one() {
+                write!(f, "{}*", c)?;
+            }
+
+            match i {
+                0 => {},
+                1 => write!(f, "{}", var)?,
+                _ => write!(f, "{}^{}", var, i)?
+            }
+        }
+
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
     }
 }
 
@@ -325,10 +376,483 @@ impl IntPoly {
     pub fn from_raw(inner: core::IntPoly, ctx: IntPolyCtx) -> Self {
         IntPoly { inner, ctx }
     }
+
+    /// The monic polynomial `(x - r_1)...(x - r_n)` in the variable `var` with
+    /// the given roots.
+    pub fn from_roots<S: Into<String>>(roots: &[Integer], var: S) -> Self {
+        let mut res = IntPoly::zero(var);
+        let n = roots.len() as c_long;
+        // SAFETY: `xs` holds `n` initialized fmpz values until it is cleared,
+        // and we only write to indices below `n`.
+        unsafe {
+            let xs = fmpz_vec::_fmpz_vec_init(n);
+            for (i, r) in roots.iter().enumerate() {
+                fmpz::fmpz_set(xs.add(i), r.as_ptr());
+            }
+            fmpz_poly::fmpz_poly_product_roots_fmpz_vec(res.as_mut_ptr(), xs, n);
+            fmpz_vec::_fmpz_vec_clear(xs, n);
+        }
+        res
+    }
+
+    /// The zero polynomial in the variable `var`, without going through an
+    /// [IntPolyRing].
+    #[inline]
+    pub fn zero<S: Into<String>>(var: S) -> Self {
+        IntPoly::from_raw(core::IntPoly::zero(), IntPolyCtx::new(var.into()))
+    }
+    
+    /// The constant polynomial one in the variable `var`, without going 
+    /// through an [IntPolyRing].
+    #[inline]
+    pub fn one<S: Into<String>>(var: S) -> Self {
+        IntPoly::from_raw(core::IntPoly::one(), IntPolyCtx::new(var.into()))
+    }
     
     pub fn context(&self) -> &IntPolyCtx {
         &self.ctx
     }
+
+    /// A copy of the polynomial in the variable `var`. Unlike 
+    /// [PolynomialRing::set_var] this gets a fresh context, so the variable
+    /// of `self` (and of any polynomials sharing its context) is unchanged.
+    #[inline]
+    pub fn with_var<S: Into<String>>(&self, var: S) -> IntPoly {
+        IntPoly::from_raw(self.inner().clone(), IntPolyCtx::new(var.into()))
+    }
+
+    /// Set the coefficient of `x^i` from anything convertible to an
+    /// [Integer], e.g. a primitive integer.
+    #[inline]
+    pub fn set_coeff_into<T: Into<Integer>>(&mut self, i: usize, coeff: T) {
+        self.set_coefficient(i, coeff.into());
+    }
+
+    /// Set several coefficients at once from `(i, coeff)` pairs, where `coeff`
+    /// becomes the coefficient of `x^i`. Other coefficients are unchanged.
+    pub fn set_coeffs<I: IntoIterator<Item = (usize, Integer)>>(&mut self, terms: I) {
+        for (i, coeff) in terms {
+            self.set_coefficient(i, coeff);
+        }
+    }
+
+    /// Apply `f` to every coefficient, including zero coefficients below the
+    /// degree.
+    #[inline]
+    pub fn map_coefficients<F: Fn(&Integer) -> Integer>(&self, f: F) -> IntPoly {
+        let mut res = self.parent().zero();
+        res.set_coeffs(self.get_coefficients().iter().map(f).enumerate());
+        res
+    }
+
+    /// Multiply by `x^n` in place.
+    #[inline]
+    pub fn shift_left(&mut self, n: usize) {
+        self.inner = self.inner().shift_left(n);
+    }
+    
+    /// Divide by `x^n` in place, discarding the `n` lowest terms.
+    #[inline]
+    pub fn shift_right(&mut self, n: usize) {
+        self.inner = self.inner().shift_right(n);
+    }
+
+    /// Keep only the `n` lowest terms in place.
+    #[inline]
+    pub fn truncate(&mut self, n: usize) {
+        // SAFETY: truncating to a length beyond the current one is a no-op.
+        unsafe { fmpz_poly::fmpz_poly_truncate(self.as_mut_ptr(), n as c_long); }
+    }
+    
+    /// The product with `x^n`.
+    #[inline]
+    pub fn shifted_left(&self, n: usize) -> IntPoly {
+        IntPoly::from_raw(self.inner().shift_left(n), self.context().clone())
+    }
+    
+    /// The quotient by `x^n`, discarding the `n` lowest terms.
+    #[inline]
+    pub fn shifted_right(&self, n: usize) -> IntPoly {
+        IntPoly::from_raw(self.inner().shift_right(n), self.context().clone())
+    }
+
+    /// The polynomial consisting of the `n` lowest terms.
+    #[inline]
+    pub fn truncated(&self, n: usize) -> IntPoly {
+        let mut res = self.clone();
+        res.truncate(n);
+        res
+    }
+
+    /// Set the polynomial to zero, keeping its allocation for reuse.
+    #[inline]
+    pub fn clear(&mut self) {
+        // SAFETY: `self` is an initialized fmpz_poly and we hold the only borrow.
+        unsafe { fmpz_poly::fmpz_poly_zero(self.as_mut_ptr()); }
+    }
+
+    /// The FLINT string representation: the length, two spaces, then the 
+    /// coefficients in increasing degree separated by single spaces, e.g.
+    /// `"3  2 3 1"` for `x^2 + 3*x + 2`.
+    pub fn get_str(&self) -> String {
+        // SAFETY: FLINT returns a nul-terminated string allocated with its own
+        // allocator, which we copy before freeing it with `flint_free`.
+        unsafe {
+            let s = fmpz_poly::fmpz_poly_get_str(self.as_ptr());
+            let res = CStr::from_ptr(s).to_string_lossy().into_owned();
+            flint::flint_free(s as *mut c_void);
+            res
+        }
+    }
+    
+    /// The human-readable string representation in the variable of `self`,
+    /// e.g. `"x^2+3*x+2"`.
+    pub fn get_str_pretty(&self) -> String {
+        let var = CString::new(self.var()).expect("variable contains a nul byte");
+        // SAFETY: `var` outlives the call, and the returned string is copied
+        // and then freed with FLINT's allocator as in `get_str`.
+        unsafe {
+            let s = fmpz_poly::fmpz_poly_get_str_pretty(self.as_ptr(), var.as_ptr());
+            let res = CStr::from_ptr(s).to_string_lossy().into_owned();
+            flint::flint_free(s as *mut c_void);
+            res
+        }
+    }
+
+    /// Parse a human-readable polynomial in the variable `var` such as 
+    /// `"x^2 + 3x + 2"` or `"-2*x^3 + x - 1"`. Whitespace is ignored, `*` 
+    /// between a coefficient and the variable is optional and repeated 
+    /// powers are summed. Use [FromStr] for the FLINT format of [get_str].
+    ///
+    /// [get_str]: IntPoly::get_str
+    pub fn from_pretty_str(s: &str, var: &str) -> Result<IntPoly, ParsePolyError> {
+        assert!(!var.is_empty());
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if s.is_empty() {
+            return Err(ParsePolyError);
+        }
+        
+        let mut res = IntPoly::zero(var);
+        let mut rest = s.as_str();
+        while !rest.is_empty() {
+            // Split off the next term together with its sign.
+            let end = rest.char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '+' || c == '-')
+                .map_or(rest.len(), |(i, _)| i);
+            let (term, tail) = rest.split_at(end);
+            rest = tail;
+
+            let (neg, term) = match term.strip_prefix('-') {
+                Some(t) => (true, t),
+                None => (false, term.strip_prefix('+').unwrap_or(term))
+            };
+            let (coeff, exp) = match term.find(var) {
+                Some(i) => {
+                    let c = &term[..i];
+                    let c = c.strip_suffix('*').unwrap_or(c);
+                    let c = if c.is_empty() {
+                        Integer::one()
+                    } else {
+                        c.parse::<Integer>().map_err(|_| ParsePolyError)?
+                    };
+                    let e = &term[i + var.len()..];
+                    let e = if e.is_empty() {
+                        1
+                    } else {
+                        e.strip_prefix('^')
+                            .ok_or(ParsePolyError)?
+                            .parse::<usize>()
+                            .map_err(|_| ParsePolyError)?
+                    };
+                    (c, e)
+                },
+                None => (term.parse::<Integer>().map_err(|_| ParsePolyError)?, 0)
+            };
+            
+            let coeff = if neg { -coeff } else { coeff };
+            let old = res.get_coefficient(exp);
+            res.set_coefficient(exp, old + coeff);
+        }
+        Ok(res)
+    }
+    
+    /// A pointer to the underlying `fmpz_poly` for passing to FLINT. It is
+    /// only valid while `self` is borrowed.
+    #[inline]
+    pub fn as_ptr(&self) -> *const fmpz_poly::fmpz_poly_struct {
+        self.inner().as_ptr()
+    }
+    
+    /// A mutable pointer to the underlying `fmpz_poly` for passing to FLINT.
+    /// It is only valid while `self` is mutably borrowed.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut fmpz_poly::fmpz_poly_struct {
+        self.inner_mut().as_mut_ptr()
+    }
+
+    /// The non-negative gcd of the coefficients.
+    #[inline]
+    pub fn content(&self) -> Integer {
+        Integer::from_raw(self.inner().content())
+    }
+
+    /// The polynomial divided by its content, normalized to have a positive
+    /// leading coefficient.
+    #[inline]
+    pub fn primitive_part(&self) -> IntPoly {
+        let mut res = self.parent().zero();
+        // SAFETY: `res` is a fresh initialized fmpz_poly distinct from `self`.
+        unsafe { 
+            fmpz_poly::fmpz_poly_primitive_part(res.as_mut_ptr(), self.as_ptr()); 
+        }
+        res
+    }
+
+    /// Whether the polynomial has content one and a positive leading 
+    /// coefficient, as the result of [IntPoly::primitive_part] does for any
+    /// nonzero polynomial.
+    #[inline]
+    pub fn is_primitive(&self) -> bool {
+        self.content().is_one() && self.leading_coeff().sign() > 0
+    }
+
+    /// The gcd of two polynomials, normalized to have a positive leading
+    /// coefficient.
+    #[inline]
+    pub fn gcd(&self, other: &IntPoly) -> IntPoly {
+        assert_eq!(self.context(), other.context());
+        IntPoly::from_raw(self.inner().gcd(other.inner()), self.context().clone())
+    }
+
+    /// The resultant of `self` and `other`.
+    #[inline]
+    pub fn resultant(&self, other: &IntPoly) -> Integer {
+        assert_eq!(self.context(), other.context());
+        let mut res = Integer::zero();
+        // SAFETY: `res` is a fresh fmpz, the inputs are initialized fmpz_polys.
+        unsafe { 
+            fmpz_poly::fmpz_poly_resultant(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); 
+        }
+        res
+    }
+
+    /// Whether `self` and `other` have no common factor of positive degree, 
+    /// i.e. their gcd is a nonzero constant. Two zero polynomials are not
+    /// coprime.
+    #[inline]
+    pub fn are_coprime(&self, other: &IntPoly) -> bool {
+        self.gcd(other).degree() == 0
+    }
+
+    /// Split the gcd of two polynomials into the gcd of their contents and
+    /// the gcd of their primitive parts (Gauss's lemma), so that
+    /// `self.gcd(other) == c * g`.
+    #[inline]
+    pub fn content_gcd(&self, other: &IntPoly) -> (Integer, IntPoly) {
+        let g = self.gcd(other);
+        (g.content(), g.primitive_part())
+    }
+
+    /// A canonical representative of `self` up to multiplication by nonzero 
+    /// integers: the primitive part, which has a positive leading 
+    /// coefficient. The zero polynomial is its own representative.
+    #[inline]
+    pub fn canonical_associate(&self) -> IntPoly {
+        self.primitive_part()
+    }
+
+    /// Division with remainder in `(Z/pZ)[x]` for a prime `p`, with the
+    /// quotient and remainder lifted back to coefficients in the symmetric
+    /// range `(-p/2, p/2]`. Panics if `other` vanishes modulo `p`.
+    pub fn div_rem_mod(&self, other: &IntPoly, p: &Integer) -> (IntPoly, IntPoly) {
+        assert_eq!(self.context(), other.context());
+        let n = p.get_ui().expect("modulus does not fit in a u64");
+        assert!(p.is_prime(), "modulus must be prime");
+        
+        let mut q = self.parent().zero();
+        let mut r = self.parent().zero();
+        // SAFETY: each nmod_poly is initialized by `nmod_poly_init` before
+        // `assume_init` and cleared exactly once. `nmod_poly_divrem` needs a
+        // nonzero divisor, which is checked first, and the clears happen 
+        // before the assertion can unwind.
+        unsafe {
+            let mut a = MaybeUninit::uninit();
+            let mut b = MaybeUninit::uninit();
+            let mut q_mod = MaybeUninit::uninit();
+            let mut r_mod = MaybeUninit::uninit();
+            nmod_poly::nmod_poly_init(a.as_mut_ptr(), n);
+            nmod_poly::nmod_poly_init(b.as_mut_ptr(), n);
+            nmod_poly::nmod_poly_init(q_mod.as_mut_ptr(), n);
+            nmod_poly::nmod_poly_init(r_mod.as_mut_ptr(), n);
+            let mut a = a.assume_init();
+            let mut b = b.assume_init();
+            let mut q_mod = q_mod.assume_init();
+            let mut r_mod = r_mod.assume_init();
+
+            fmpz_poly::fmpz_poly_get_nmod_poly(&mut a, self.as_ptr());
+            fmpz_poly::fmpz_poly_get_nmod_poly(&mut b, other.as_ptr());
+            let zero_divisor = nmod_poly::nmod_poly_is_zero(&b) != 0;
+            if !zero_divisor {
+                nmod_poly::nmod_poly_divrem(&mut q_mod, &mut r_mod, &a, &b);
+                fmpz_poly::fmpz_poly_set_nmod_poly(q.as_mut_ptr(), &q_mod);
+                fmpz_poly::fmpz_poly_set_nmod_poly(r.as_mut_ptr(), &r_mod);
+            }
+            
+            nmod_poly::nmod_poly_clear(&mut a);
+            nmod_poly::nmod_poly_clear(&mut b);
+            nmod_poly::nmod_poly_clear(&mut q_mod);
+            nmod_poly::nmod_poly_clear(&mut r_mod);
+            assert!(!zero_divisor, "division by a polynomial vanishing mod p");
+        }
+        (q, r)
+    }
+
+    /// The values `self(x) mod m` in `[0, m)` for each modulus `m`, computed
+    /// by Horner's rule with the coefficients reduced once per modulus so
+    /// intermediate values stay bounded by `m^2`. Panics if a modulus is not
+    /// positive.
+    pub fn evaluate_mod_multi(&self, x: &Integer, moduli: &[Integer]) -> Vec<Integer> {
+        let coeffs = self.get_coefficients();
+        moduli.iter().map(|m| {
+            assert!(m.sign() > 0, "modulus must be positive");
+            let mut res = Integer::zero();
+            // SAFETY: every pointer comes from a live Integer, FLINT allows the
+            // output of fmpz arithmetic to alias an input, and `m` is positive
+            // as `fmpz_mod` requires.
+            unsafe {
+                let mut xm = Integer::zero();
+                let mut c = Integer::zero();
+                fmpz::fmpz_mod(xm.as_mut_ptr(), x.as_ptr(), m.as_ptr());
+                for a in coeffs.iter().rev() {
+                    fmpz::fmpz_mod(c.as_mut_ptr(), a.as_ptr(), m.as_ptr());
+                    fmpz::fmpz_mul(res.as_mut_ptr(), res.as_ptr(), xm.as_ptr());
+                    fmpz::fmpz_add(res.as_mut_ptr(), res.as_ptr(), c.as_ptr());
+                    fmpz::fmpz_mod(res.as_mut_ptr(), res.as_ptr(), m.as_ptr());
+                }
+            }
+            res
+        }).collect()
+    }
+
+    /// A fast one-sided irreducibility test built on reduction modulo the
+    /// prime `p`. Returns `true` if the polynomial is primitive of positive
+    /// degree and its reduction modulo `p` has the same degree and is
+    /// irreducible. A `true` result proves that `self` is irreducible over
+    /// the integers. A `false` result proves nothing: reducible polynomials
+    /// always give `false`, but so do some irreducible ones.
+    pub fn is_probable_irreducible(&self, p: &Integer) -> bool {
+        let n = p.get_ui().expect("modulus does not fit in a u64");
+        assert!(p.is_prime(), "modulus must be prime");
+        if self.degree() < 1 || !self.content().is_one() {
+            return false;
+        }
+        // SAFETY: the nmod_poly is initialized before `assume_init` and 
+        // cleared exactly once, and `p` is a prime fitting in a word.
+        unsafe {
+            let mut a = MaybeUninit::uninit();
+            nmod_poly::nmod_poly_init(a.as_mut_ptr(), n);
+            let mut a = a.assume_init();
+            fmpz_poly::fmpz_poly_get_nmod_poly(&mut a, self.as_ptr());
+            let res = nmod_poly::nmod_poly_degree(&a) == self.degree() 
+                && nmod_poly::nmod_poly_is_irreducible(&a) != 0;
+            nmod_poly::nmod_poly_clear(&mut a);
+            res
+        }
+    }
+
+    /// Whether `self` and `other` have the same canonical associate, so for
+    /// example `p`, `-p` and `2*p` are all identified.
+    #[inline]
+    pub fn eq_canonical(&self, other: &IntPoly) -> bool {
+        self.canonical_associate() == other.canonical_associate()
+    }
+
+    /// Make the polynomial monic without leaving the integers. Returns `None`
+    /// unless the leading coefficient is a unit.
+    pub fn monic_within_z(&self) -> Option<IntPoly> {
+        let lc = self.leading_coeff();
+        if lc.is_one() {
+            Some(self.clone())
+        } else if (-lc).is_one() {
+            Some(-self.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Division with remainder, `self = q * other + r`. The coefficients of 
+    /// `r` are only reduced modulo the leading coefficient of `other`, so this
+    /// agrees with division over the rationals exactly when the leading
+    /// coefficient of `other` is a unit or the division is exact.
+    #[inline]
+    pub fn divrem(&self, other: &IntPoly) -> (IntPoly, IntPoly) {
+        assert_eq!(self.context(), other.context());
+        assert!(!other.is_zero(), "division by the zero polynomial");
+        let (q, r) = self.inner().divrem(other.inner());
+        let ctx = self.context();
+        (IntPoly::from_raw(q, ctx.clone()), IntPoly::from_raw(r, ctx.clone()))
+    }
+
+    /// Exact division. Returns `None` if `other` is zero or does not divide
+    /// `self` over the integers.
+    #[inline]
+    pub fn try_div(&self, other: &IntPoly) -> Option<IntPoly> {
+        assert_eq!(self.context(), other.context());
+        if other.is_zero() {
+            return None;
+        }
+        let mut q = self.parent().zero();
+        // SAFETY: `q` is a fresh fmpz_poly and `other` is nonzero.
+        let divides = unsafe { 
+            fmpz_poly::fmpz_poly_divides(q.as_mut_ptr(), self.as_ptr(), other.as_ptr()) 
+        };
+        if divides != 0 {
+            Some(q)
+        } else {
+            None
+        }
+    }
+
+    /// The product `self * other` truncated to its first `n` coefficients.
+    #[inline]
+    pub fn mullow(&self, other: &IntPoly, n: c_long) -> IntPoly {
+        assert_eq!(self.context(), other.context());
+        assert!(n >= 0);
+        let mut res = self.parent().zero();
+        // SAFETY: `res` is distinct from the inputs and `n` is non-negative.
+        unsafe { 
+            fmpz_poly::fmpz_poly_mullow(res.as_mut_ptr(), self.as_ptr(), other.as_ptr(), n); 
+        }
+        res
+    }
+
+    /// Multiply using Kronecker substitution, which packs the coefficients 
+    /// into one large integer. This tends to win for polynomials with many
+    /// small coefficients. The `Mul` operator picks an algorithm heuristically.
+    #[inline]
+    pub fn mul_ks(&self, other: &IntPoly) -> IntPoly {
+        assert_eq!(self.context(), other.context());
+        let mut res = self.parent().zero();
+        // SAFETY: `res` is a fresh fmpz_poly distinct from both inputs.
+        unsafe { 
+            fmpz_poly::fmpz_poly_mul_KS(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); 
+        }
+        res
+    }
+    
+    /// Multiply using the schoolbook algorithm, which is fastest for very short
+    /// polynomials.
+    #[inline]
+    pub fn mul_classical(&self, other: &IntPoly) -> IntPoly {
+        assert_eq!(self.context(), other.context());
+        let mut res = self.parent().zero();
+        // SAFETY: `res` is a fresh fmpz_poly distinct from both inputs.
+        unsafe { 
+            fmpz_poly::fmpz_poly_mul_classical(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); 
+        }
+        res
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////
@@ -360,6 +884,38 @@ impl_from! {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParsePolyError;
+
+impl fmt::Display for ParsePolyError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid polynomial string")
+    }
+}
+
+impl std::error::Error for ParsePolyError {}
+
+/// Parse the FLINT string format produced by [IntPoly::get_str]. See
+/// [IntPoly::from_pretty_str] for human-readable input.
+impl FromStr for IntPoly {
+    type Err = ParsePolyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let c_str = CString::new(s).map_err(|_| ParsePolyError)?;
+        let mut res = IntPoly::default();
+        // SAFETY: `c_str` is nul-terminated and outlives the call.
+        let flag = unsafe { 
+            fmpz_poly::fmpz_poly_set_str(res.as_mut_ptr(), c_str.as_ptr()) 
+        };
+        if flag == 0 {
+            Ok(res)
+        } else {
+            Err(ParsePolyError)
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////
 // Ops
 ///////////////////////////////////////////////////////////////////////
@@ -397,6 +953,36 @@ derive_binop! {
     AssignRem, assign_rem
 }
 
+// Exact division over the integers, panics if the divisor is zero or does not
+// divide the dividend. See `IntPoly::try_div` for a non-panicking version.
+impl_binop! {
+    IntPoly, IntPoly, IntPoly
+    Div {div}
+    {
+        fn div(self, rhs: &IntPoly) -> IntPoly {
+            self.try_div(rhs).expect("inexact polynomial division")
+        }
+    }
+    DivAssign {div_assign}
+    {
+        fn div_assign(&mut self, rhs: &IntPoly) {
+            *self = self.try_div(rhs).expect("inexact polynomial division");
+        }
+    }
+    DivFrom {div_from}
+    {
+        fn div_from(&mut self, lhs: &IntPoly) {
+            *self = lhs.try_div(self).expect("inexact polynomial division");
+        }
+    }
+    AssignDiv {assign_div}
+    {
+        fn assign_div(&mut self, lhs: &IntPoly, rhs: &IntPoly) {
+            *self = lhs.try_div(rhs).expect("inexact polynomial division");
+        }
+    }
+}
+
 derive_binop! {
     ctx
     op_assign
@@ -441,6 +1027,20 @@ derive_binop! {
     AssignRem, assign_rem
 }
 
+///////////////////////////////////////////////////////////////////////
+// Functions
+///////////////////////////////////////////////////////////////////////
+
+/// Eliminate the variable from `f = g = 0`, i.e. compute the resultant of `f`
+/// and `g`. It vanishes exactly when `f` and `g` have a common complex root,
+/// equivalently a common nonconstant factor. Panics if either polynomial is 
+/// constant.
+#[inline]
+pub fn eliminate(f: &IntPoly, g: &IntPoly) -> Integer {
+    assert!(f.degree() > 0 && g.degree() > 0, "polynomials must be nonconstant");
+    f.resultant(g)
+}
+
 /*
 derive_binop! {
     IntPoly, {u64}, IntPoly
@@ -486,3 +1086,311 @@ derive_scalar_binops! {
     AssignAdd, assign_add
 }*/
 
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::str::FromStr;
+
+    fn poly(s: &str) -> IntPoly {
+        IntPoly::from_pretty_str(s, "x").unwrap()
+    }
+
+    #[test]
+    fn str_round_trip() {
+        let f = poly("x^2 + 3x + 2");
+        assert_eq!(f.get_str(), "3  2 3 1");
+        assert_eq!(IntPoly::from_str(&f.get_str()).unwrap(), f);
+
+        let g = poly("-2*x^3 + x - 1");
+        assert_eq!(g.to_string(), "-2*x^3 + x - 1");
+        assert_eq!(poly(&g.to_string()), g);
+        assert!(IntPoly::from_pretty_str("x^", "x").is_err());
+    }
+
+    #[test]
+    fn canonical_associate() {
+        let f = poly("-2x - 4");
+        assert_eq!(f.canonical_associate(), poly("x + 2"));
+        assert!(f.eq_canonical(&poly("3x + 6")));
+        assert!(!f.eq_canonical(&poly("x - 2")));
+    }
+
+    #[test]
+    fn div_rem_mod_7() {
+        let p = Integer::from(7);
+        let f = poly("x^3 + 2x + 5");
+        let g = poly("3x + 1");
+        let (q, r) = f.div_rem_mod(&g, &p);
+        assert!(r.degree() < g.degree());
+        
+        let d = &f - &(&q * &g + &r);
+        for c in d.get_coefficients() {
+            assert!((&c % &p).is_zero());
+        }
+        // Lifted coefficients are centered, so at most 3 in absolute value.
+        for c in q.get_coefficients().iter().chain(r.get_coefficients().iter()) {
+            assert!(c.abs().bits() <= 2);
+        }
+    }
+
+    #[test]
+    fn from_roots() {
+        let roots = [1, 2, 3].map(Integer::from);
+        assert_eq!(IntPoly::from_roots(&roots, "x"), poly("x^3 - 6x^2 + 11x - 6"));
+        assert_eq!(IntPoly::from_roots(&[], "x"), poly("1"));
+    }
+
+    #[test]
+    fn evaluate_mod_multi() {
+        let f = poly("3x^4 - 7x^2 + x + 12345678901234567890");
+        let x = Integer::from_str("-98765432109876543210").unwrap();
+        let moduli = ["7", "1000003", "18446744073709551629"]
+            .map(|m| Integer::from_str(m).unwrap());
+        
+        // Evaluate exactly by Horner's rule, then reduce into [0, m).
+        let exact = f.get_coefficients()
+            .iter()
+            .rev()
+            .fold(Integer::zero(), |acc, c| acc * &x + c);
+        let expected: Vec<Integer> = moduli.iter()
+            .map(|m| (&(&exact % m) + m) % m)
+            .collect();
+        assert_eq!(f.evaluate_mod_multi(&x, &moduli), expected);
+    }
+
+    #[test]
+    fn content_gcd() {
+        // 6(x + 1)(x - 2) and 4(x + 1)(x + 3) share the content 2 and the
+        // primitive factor x + 1.
+        let f = poly("6x^2 - 6x - 12");
+        let g = poly("4x^2 + 16x + 12");
+        let (c, h) = f.content_gcd(&g);
+        assert_eq!(c, Integer::from(2));
+        assert_eq!(h, poly("x + 1"));
+        assert_eq!(f.gcd(&g), &h * &c);
+    }
+
+    #[test]
+    fn monic_within_z() {
+        assert_eq!(poly("x^2 + 3").monic_within_z(), Some(poly("x^2 + 3")));
+        assert_eq!(poly("-x^2 + 3").monic_within_z(), Some(poly("x^2 - 3")));
+        assert_eq!(poly("2x + 1").monic_within_z(), None);
+        assert_eq!(poly("-3").monic_within_z(), None);
+    }
+
+    #[test]
+    fn ring_eq_and_clone() {
+        let zz = Integers::init();
+        let r = IntPolyRing::init(&zz, "x");
+        assert_eq!(r, IntPolyRing::init(&zz, "x"));
+        assert_ne!(r, IntPolyRing::init(&zz, "y"));
+        
+        // Cloning shares the context instead of copying it.
+        let s = r.clone();
+        assert!(std::rc::Rc::ptr_eq(&r.context().0, &s.context().0));
+    }
+
+    #[test]
+    fn with_var() {
+        let f = poly("x^2 - 2x");
+        let g = f.with_var("t");
+        assert_eq!(g.to_string(), "t^2 - 2*t");
+        assert_eq!(g.get_str_pretty(), "t^2-2*t");
+        assert_eq!(f.to_string(), "x^2 - 2*x");
+        assert_eq!(f.var(), "x");
+    }
+
+    #[test]
+    fn div_rem() {
+        let f = poly("x^2 - x - 2");
+        assert_eq!(&f / &poly("x + 1"), poly("x - 2"));
+        
+        let g = poly("x^2 + 1");
+        let h = poly("x - 1");
+        assert_eq!(&g % &h, poly("2"));
+        assert_eq!(g.divrem(&h), (poly("x + 1"), poly("2")));
+        assert_eq!(g.try_div(&h), None);
+        assert_eq!(g.try_div(&IntPoly::zero("x")), None);
+    }
+
+    #[test]
+    fn mullow() {
+        let a = poly("3x^3 - x^2 + 2x + 1");
+        let b = poly("x^2 + 5x - 4");
+        for n in 0..7 {
+            assert_eq!(a.mullow(&b, n), (&a * &b).truncated(n as usize));
+        }
+    }
+
+    #[test]
+    fn mul_algorithms_agree() {
+        let a = poly("3x^3 - x^2 + 2x + 1");
+        let b = poly("-x^4 + 5x - 4");
+        let c = poly("12");
+        for (f, g) in [(&a, &b), (&b, &a), (&a, &c), (&a, &a)] {
+            let prod = f * g;
+            assert_eq!(f.mul_ks(g), prod);
+            assert_eq!(f.mul_classical(g), prod);
+        }
+        assert_eq!(a.mul_ks(&b).get_coefficient(7), Integer::from(-3));
+    }
+
+    #[test]
+    fn neg() {
+        let p = poly("3x^3 - x^2 + 2x + 1");
+        assert!((-&p + &p).is_zero());
+        assert!((-p.clone() + p.clone()).is_zero());
+        assert_eq!(-&p, poly("-3x^3 + x^2 - 2x - 1"));
+        assert!((-IntPoly::zero("x")).is_zero());
+    }
+
+    #[test]
+    fn is_probable_irreducible() {
+        let p = |n: u64| Integer::from(n);
+        assert!(poly("x^2 + 1").is_probable_irreducible(&p(3)));
+        assert!(poly("x^3 - x - 1").is_probable_irreducible(&p(2)));
+        // Reducible and non-primitive polynomials are always rejected.
+        assert!(!poly("x^2 - 1").is_probable_irreducible(&p(3)));
+        assert!(!poly("x^2 - 1").is_probable_irreducible(&p(5)));
+        assert!(!poly("2x^2 + 2").is_probable_irreducible(&p(3)));
+        // Irreducible over the integers, but the degree drops mod 5 or the
+        // reduction splits, so nothing is proved.
+        assert!(!poly("5x^2 + x + 1").is_probable_irreducible(&p(5)));
+        assert!(!poly("x^2 + 1").is_probable_irreducible(&p(5)));
+    }
+
+    #[test]
+    fn scalar_mul() {
+        let p = poly("3x^3 - x^2 + 2x + 1");
+        let three = Integer::from(3);
+        let expected = poly("9x^3 - 3x^2 + 6x + 3");
+        assert_eq!(&p * &three, expected);
+        assert_eq!(&three * &p, expected);
+        assert_eq!(p.clone() * three.clone(), expected);
+        for (c, d) in (&p * &three).get_coefficients().iter().zip(p.get_coefficients()) {
+            assert_eq!(c, &(&d * &three));
+        }
+        
+        let mut q = p.clone();
+        q *= &three;
+        assert_eq!(q, expected);
+    }
+
+    #[test]
+    fn set_coeff_into() {
+        let mut p = IntPoly::zero("x");
+        p.set_coeff_into(0, 7u8);
+        p.set_coeff_into(1, -2i32);
+        p.set_coeff_into(3, u64::MAX);
+        p.set_coeff_into(4, i64::MIN);
+        p.set_coeff_into(5, Integer::from(5));
+        p.set_coeff_into(1, 3usize);
+        assert_eq!(p.degree(), 5);
+        assert_eq!(p.get_coefficient(0), Integer::from(7));
+        assert_eq!(p.get_coefficient(1), Integer::from(3));
+        assert!(p.get_coefficient(2).is_zero());
+        assert_eq!(p.get_coefficient(3), Integer::from(u64::MAX));
+        assert_eq!(p.get_coefficient(4), Integer::from(i64::MIN));
+        assert_eq!(p.get_coefficient(5), Integer::from(5));
+    }
+
+    #[test]
+    fn zero_and_one() {
+        let zero = IntPoly::zero("x");
+        let one = IntPoly::one("x");
+        assert!(zero.is_zero());
+        assert!(one.is_one());
+        assert!(!zero.is_one());
+        assert_eq!(one.degree(), 0);
+        assert_eq!(zero.var(), "x");
+        assert_eq!(IntPoly::one("t").var(), "t");
+        assert_eq!(poly("1"), one);
+    }
+
+    #[test]
+    fn set_coeffs_and_clear() {
+        let mut p = poly("x + 1");
+        p.set_coeffs([(3, Integer::from(-4)), (0, Integer::from(2)), (5, Integer::zero())]);
+        assert_eq!(p, poly("-4x^3 + x + 2"));
+        
+        let mut q = IntPoly::zero("x");
+        q.set_coeffs(p.get_coefficients().into_iter().enumerate());
+        assert_eq!(q, p);
+        
+        q.clear();
+        assert!(q.is_zero());
+        assert_eq!(q.var(), "x");
+        q.set_coeffs([(1, Integer::from(7))]);
+        assert_eq!(q, poly("7x"));
+    }
+
+    #[test]
+    fn eliminate() {
+        // x^2 - 1 and x^2 + x - 2 share the root 1.
+        let f = poly("x^2 - 1");
+        assert!(super::eliminate(&f, &poly("x^2 + x - 2")).is_zero());
+        assert!(super::eliminate(&poly("x - 3"), &poly("x^2 - 9")).is_zero());
+        // The product of g over the roots 1 and -1 of f.
+        assert_eq!(super::eliminate(&f, &poly("x - 2")), Integer::from(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "polynomials must be nonconstant")]
+    fn eliminate_constant() {
+        super::eliminate(&poly("x^2 - 1"), &poly("5"));
+    }
+
+    #[test]
+    fn primitive_part() {
+        for s in ["6x^2 + 4x - 2", "-6x^2 + 4x - 2", "-3", "x + 1", "12x^5 - 18"] {
+            let p = poly(s);
+            let pp = p.primitive_part();
+            assert!(pp.is_primitive());
+            assert!(pp.eq_canonical(&p));
+        }
+        assert_eq!(poly("-6x^2 + 4x - 2").primitive_part(), poly("3x^2 - 2x + 1"));
+        assert!(!poly("6x^2 + 4x - 2").is_primitive());
+        assert!(!poly("-x + 1").is_primitive());
+        assert!(IntPoly::zero("x").primitive_part().is_zero());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(IntPoly::zero("x").to_string(), "0");
+        assert_eq!(poly("-5").to_string(), "-5");
+        assert_eq!(poly("x").to_string(), "x");
+        assert_eq!(IntPoly::from_pretty_str("t", "t").unwrap().to_string(), "t");
+        assert_eq!(poly("-x^3 + 2x - 1").to_string(), "-x^3 + 2*x - 1");
+        assert_eq!(poly("3x^2 - x").to_string(), "3*x^2 - x");
+    }
+
+    #[test]
+    fn are_coprime() {
+        assert!(poly("x^2 + 1").are_coprime(&poly("x^2 - 1")));
+        assert!(!poly("x^2 - 1").are_coprime(&poly("x - 1")));
+        assert!(!poly("2x + 2").are_coprime(&poly("x + 1")));
+        assert!(poly("x").are_coprime(&poly("x + 1")));
+    }
+
+    #[test]
+    fn map_coefficients() {
+        let p = poly("-3x^3 + 2x - 1");
+        assert_eq!(p.map_coefficients(|c| c.abs()), poly("3x^3 + 2x + 1"));
+        assert_eq!(p.map_coefficients(|c| c * Integer::from(2)), poly("-6x^3 + 4x - 2"));
+        assert!(p.map_coefficients(|_| Integer::zero()).is_zero());
+        assert_eq!(p, poly("-3x^3 + 2x - 1"));
+    }
+
+    #[test]
+    fn truncated() {
+        let p = poly("3x^3 - x^2 + 2x + 1");
+        assert_eq!(p.truncated(2), poly("2x + 1"));
+        assert_eq!(p, poly("3x^3 - x^2 + 2x + 1"));
+        assert!(p.truncated(0).is_zero());
+        assert_eq!(p.truncated(10), p);
+        
+        let mut q = p.clone();
+        q.truncate(2);
+        assert_eq!(q, p.truncated(2));
+    }
+}
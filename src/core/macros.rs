@@ -213,6 +213,54 @@ macro_rules! derive_unop {
 }
 
 macro_rules! derive_binop {
+    (
+        // a + primitive = a, for each primitive type in the list
+        $kw:ident
+        op_assign
+        $lhs:ident, {$rhs:ident $($rest:ident)*}, $out:ident
+        $($ops:tt)+
+    ) => {
+        derive_binop!{@primitive $kw $lhs, $rhs, $out $($ops)+}
+        derive_binop!{$kw op_assign $lhs, {$($rest)*}, $out $($ops)+}
+    };
+    (
+        $kw:ident
+        op_assign
+        $lhs:ident, {}, $out:ident
+        $($ops:tt)+
+    ) => {};
+    (
+        @primitive $kw:ident
+        $lhs:ident, $rhs:ident, $out:ident
+        $(
+            $op:ident, $meth:ident
+            $op_assign:ident, $meth_assign:ident
+            $assign_op:ident, $assign_meth:ident
+        )+
+    ) => ($(
+        impl_binop! {
+            op_assign
+            $lhs, $rhs, $out
+            $op {$meth}
+            {
+                fn $meth(self, rhs: &$rhs) -> $out {
+                    derive_binop!(@call_primitive $kw, $meth, self, rhs, $out)
+                }
+            }
+            $op_assign {$meth_assign}
+            {
+                fn $meth_assign(&mut self, rhs: &$rhs) {
+                    self.inner_mut().$meth_assign(rhs);
+                }
+            }
+            $assign_op {$assign_meth}
+            {
+                fn $assign_meth(&mut self, lhs: &$lhs, rhs: &$rhs) {
+                    self.inner_mut().$assign_meth(lhs.inner(), rhs);
+                }
+            }
+        }
+    )+);
     (
         $kw:ident
         $lhs:ident, $rhs:ident, $out:ident
@@ -344,6 +392,12 @@ macro_rules! derive_binop {
             }
         }
     )+);
+    (@call_primitive ctx, $meth:ident, $lhs:ident, $rhs:ident, $out:ident) => {
+        $out::from_raw($lhs.inner().$meth($rhs), $lhs.context().clone())
+    };
+    (@call_primitive $kw:ident, $meth:ident, $lhs:ident, $rhs:ident, $out:ident) => {
+        $out::from_raw($lhs.inner().$meth($rhs))
+    };
     (@call ctx_rhs, $meth:ident, $lhs:ident, $rhs:ident, $out:ident) => {
         //assert_eq!($lhs.context(), $rhs.context())
         $out::from_raw($lhs.inner().$meth($rhs.inner()), $rhs.context().clone())
@@ -15,14 +15,12 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-// Move Poly, Mat etc. into inertia. need them to be local types.
-// (Leave GenericPoly etc. in inertia-generic)
-// or we can't impl Add<i32> for Poly<Integer> etc.
-
-use inertia_generic::poly::{Poly, IntoPolyRing, InnerPoly};
+// Poly, Mat etc. need to be local types or we can't impl Add<Integer> for
+// Poly<T> etc.
 
 use inertia_algebra::ops::*;
-use crate::{Integer, Integers, IntPoly};
+use crate::Integer;
+use crate::poly::{Poly, IntoPolyRing, InnerPoly};
 
 // Derive scalar/coefficient binops for wrapper types from inertia-generic.
 macro_rules! derive_wrapper_binops {
@@ -47,9 +45,41 @@ macro_rules! derive_wrapper_binops {
         {
             type Output = $wrapper<T>;
             fn $meth(self, rhs: $scalar) -> Self::Output {
-                Poly::from_raw(self.into_inner().$meth(rhs))
+                $wrapper::from_raw(self.into_inner().$meth(rhs))
+            }
+        }
+        
+        impl<'a, T> $op<&'a $scalar> for $wrapper<T>
+        where
+            T: $intoinner,
+            $inner<T>: $op<&'a $scalar, Output=$inner<T>>
+        {
+            type Output = $wrapper<T>;
+            fn $meth(self, rhs: &'a $scalar) -> Self::Output {
+                $wrapper::from_raw(self.into_inner().$meth(rhs))
+            }
+        }
+        
+        impl<'a, T> $op<$scalar> for &'a $wrapper<T>
+        where
+            T: $intoinner,
+            &'a $inner<T>: $op<$scalar, Output=$inner<T>>
+        {
+            type Output = $wrapper<T>;
+            fn $meth(self, rhs: $scalar) -> Self::Output {
+                $wrapper::from_raw(self.inner().$meth(rhs))
+            }
+        }
+        
+        impl<'a, 'b, T> $op<&'b $scalar> for &'a $wrapper<T>
+        where
+            T: $intoinner,
+            &'a $inner<T>: $op<&'b $scalar, Output=$inner<T>>
+        {
+            type Output = $wrapper<T>;
+            fn $meth(self, rhs: &'b $scalar) -> Self::Output {
+                $wrapper::from_raw(self.inner().$meth(rhs))
             }
-
         }
         
         impl<T> $op<$wrapper<T>> for $scalar
@@ -59,12 +89,73 @@ macro_rules! derive_wrapper_binops {
         {
             type Output = $wrapper<T>;
             fn $meth(self, rhs: $wrapper<T>) -> Self::Output {
-                Poly::from_raw(self.$meth(rhs.into_inner()))
+                $wrapper::from_raw(self.$meth(rhs.into_inner()))
+            }
+        }
+        
+        impl<'a, T> $op<$wrapper<T>> for &'a $scalar
+        where
+            T: $intoinner,
+            &'a $scalar: $op<$inner<T>, Output=$inner<T>>
+        {
+            type Output = $wrapper<T>;
+            fn $meth(self, rhs: $wrapper<T>) -> Self::Output {
+                $wrapper::from_raw(self.$meth(rhs.into_inner()))
+            }
+        }
+        
+        impl<'a, T> $op<&'a $wrapper<T>> for $scalar
+        where
+            T: $intoinner,
+            $scalar: $op<&'a $inner<T>, Output=$inner<T>>
+        {
+            type Output = $wrapper<T>;
+            fn $meth(self, rhs: &'a $wrapper<T>) -> Self::Output {
+                $wrapper::from_raw(self.$meth(rhs.inner()))
+            }
+        }
+        
+        impl<'a, 'b, T> $op<&'b $wrapper<T>> for &'a $scalar
+        where
+            T: $intoinner,
+            &'a $scalar: $op<&'b $inner<T>, Output=$inner<T>>
+        {
+            type Output = $wrapper<T>;
+            fn $meth(self, rhs: &'b $wrapper<T>) -> Self::Output {
+                $wrapper::from_raw(self.$meth(rhs.inner()))
             }
-
         }
     }
 }
 
 derive_wrapper_binops!{poly Integer, Add, add}
+derive_wrapper_binops!{poly Integer, Sub, sub}
 derive_wrapper_binops!{poly Integer, Mul, mul}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn coeffs<const N: usize>(cs: [i32; N]) -> Vec<Integer> {
+        cs.map(Integer::from).to_vec()
+    }
+
+    #[test]
+    fn scalar_ops() {
+        // f = x^2 + 1
+        let zx = PolyRing::init(&Integers::init(), "x");
+        let x = zx.gen();
+        let f = &x * &x + Integer::from(1);
+        let two = Integer::from(2);
+        
+        assert_eq!((f.clone() * Integer::from(3)).get_coefficients(), coeffs([3, 0, 3]));
+        assert_eq!((&f * &two).get_coefficients(), coeffs([2, 0, 2]));
+        assert_eq!((&two * &f).get_coefficients(), coeffs([2, 0, 2]));
+        
+        assert_eq!((&f + &two).get_coefficients(), coeffs([3, 0, 1]));
+        assert_eq!((two.clone() + f.clone()).get_coefficients(), coeffs([3, 0, 1]));
+        assert_eq!((&f - Integer::from(1)).get_coefficients(), coeffs([0, 0, 1]));
+        assert_eq!((&two - &f).get_coefficients(), coeffs([1, 0, -1]));
+        assert_eq!((Integer::from(5) - f).get_coefficients(), coeffs([4, 0, -1]));
+    }
+}
@@ -26,13 +26,18 @@ pub use inertia_generic::mat::*;
 #[macro_use]
 pub mod macros;
 
+pub mod traits;
+pub use self::traits::*;
+
 pub mod poly;
 pub use self::poly::*;
 
 pub mod mat;
 pub use self::mat::*;
 
-//pub mod generic;
+mod generic;
+
+pub mod runtime;
 
 pub mod core;
 pub use self::core::integer::*;
@@ -22,6 +22,8 @@ use inertia_algebra::ops::NewElement;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
+use crate::poly::{Poly, PolyRing, IntoPolyRing};
+
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
@@ -64,6 +66,29 @@ impl<T: IntoMatSpace> MatSpace<T> {
     pub fn into_inner(self) -> InnerMatSpace<T> {
         self.inner
     }
+
+    /// The matrix in this space whose `(i, j)` entry is `f(i, j)`.
+    pub fn from_fn<F: Fn(usize, usize) -> Elem<T>>(&self, f: F) -> Mat<T> {
+        let mut res = self.zero();
+        for i in 0..self.nrows() {
+            for j in 0..self.ncols() {
+                res.set_entry(i, j, f(i, j));
+            }
+        }
+        res
+    }
+
+    /// The matrix in this space with the given entries in row-major order.
+    /// Panics if `data` does not have exactly `nrows * ncols` entries.
+    pub fn from_flat(&self, data: &[Elem<T>]) -> Mat<T> {
+        let ncols = self.ncols();
+        assert_eq!(
+            data.len(), 
+            self.nrows() * ncols, 
+            "number of entries does not match the matrix dimensions"
+        );
+        self.from_fn(|i, j| data[i * ncols + j].clone())
+    }
 }
 
 impl<S, T: IntoMatSpace> NewElement<S> for MatSpace<T>
@@ -191,6 +216,105 @@ impl<T: IntoMatSpace> Mat<T> {
     pub fn into_inner(self) -> InnerMat<T> {
         self.inner
     }
+
+    /// The entries in row-major order, as taken by `MatSpace::from_flat`.
+    pub fn to_flat(&self) -> Vec<Elem<T>> {
+        let mut res = Vec::with_capacity(self.nrows() * self.ncols());
+        for i in 0..self.nrows() {
+            for j in 0..self.ncols() {
+                res.push(self.get_entry(i, j).unwrap());
+            }
+        }
+        res
+    }
+
+    /// Whether all entries `(i, j)` with `pred(i, j)` are zero, stopping at
+    /// the first nonzero one.
+    fn is_zero_where<F: Fn(usize, usize) -> bool>(&self, pred: F) -> bool {
+        (0..self.nrows()).all(|i| {
+            (0..self.ncols())
+                .filter(|&j| pred(i, j))
+                .all(|j| self.get_entry(i, j).unwrap().is_zero())
+        })
+    }
+
+    /// Whether every entry off the main diagonal is zero. Rectangular
+    /// matrices are allowed.
+    #[inline]
+    pub fn is_diagonal(&self) -> bool {
+        self.is_zero_where(|i, j| i != j)
+    }
+
+    /// Whether every entry below the main diagonal is zero.
+    #[inline]
+    pub fn is_upper_triangular(&self) -> bool {
+        self.is_zero_where(|i, j| i > j)
+    }
+
+    /// Whether every entry above the main diagonal is zero.
+    #[inline]
+    pub fn is_lower_triangular(&self) -> bool {
+        self.is_zero_where(|i, j| i < j)
+    }
+}
+
+impl<T: IntoMatSpace> Mat<T> where Elem<T>: PartialEq {
+    /// Whether the matrix is square and equal to its transpose.
+    pub fn is_symmetric(&self) -> bool {
+        let n = self.nrows();
+        n == self.ncols() && (0..n).all(|i| {
+            (0..i).all(|j| self.get_entry(i, j) == self.get_entry(j, i))
+        })
+    }
+}
+
+impl<T: IntoMatSpace + IntoPolyRing> Mat<T> {
+    /// The characteristic polynomial `det(x*I - self)` in the variable `var`.
+    /// This uses Berkowitz's algorithm, which needs no divisions, so it works
+    /// over any commutative base ring.
+    pub fn charpoly<S: Into<String>>(&self, var: S) -> Poly<T> {
+        let n = self.nrows();
+        assert_eq!(n, self.ncols(), "matrix must be square");
+        
+        let ring = self.base_ring();
+        let zero = ring.zero();
+        let one = ring.one();
+        let entries: Vec<Vec<Elem<T>>> = (0..n)
+            .map(|i| (0..n).map(|j| self.get_entry(i, j).unwrap()).collect())
+            .collect();
+        let a = |i: usize, j: usize| &entries[i][j];
+        let neg = |x: &Elem<T>| <Elem<T> as TwoSidedInverse<Additive>>::two_sided_inverse(x);
+        let dot = |row: &mut dyn Iterator<Item=(&Elem<T>, &Elem<T>)>| {
+            row.fold(zero.clone(), |s, (x, y)| s.op(Additive, &x.op(Multiplicative, y)))
+        };
+        
+        // Coefficients of the characteristic polynomial of the leading r x r
+        // submatrix M, from the highest degree down.
+        let mut v = vec![one.clone()];
+        for r in 0..n {
+            // Write the leading (r+1) x (r+1) submatrix as [[M, C], [R, a_rr]].
+            // The first column of the Toeplitz matrix taking v to the next 
+            // coefficient vector is 1, -a_rr, -RC, -RMC, ..., -RM^(r-1)C.
+            let mut col = vec![one.clone(), neg(a(r, r))];
+            let mut w: Vec<Elem<T>> = (0..r).map(|i| a(i, r).clone()).collect();
+            for _ in 0..r {
+                col.push(neg(&dot(&mut (0..r).map(|j| (a(r, j), &w[j])))));
+                w = (0..r)
+                    .map(|i| dot(&mut (0..r).map(|j| (a(i, j), &w[j]))))
+                    .collect();
+            }
+
+            v = (0..r + 2)
+                .map(|i| dot(&mut (0..=i.min(r)).map(|j| (&col[i - j], &v[j]))))
+                .collect();
+        }
+
+        let mut res = PolyRing::<T>::init(ring, var).zero();
+        for (k, c) in v.into_iter().enumerate() {
+            res.set_coefficient(n - k, c);
+        }
+        res
+    }
 }
 
 impl<T: IntoMatSpace> fmt::Display for Mat<T>
@@ -342,3 +466,167 @@ impl<T: IntoMatSpace> MatrixSpaceElement<T> for Mat<T> {
         self.inner().is_generic()
     }
 }
+
+///////////////////////////////////////////////////////////////////
+// Functions
+///////////////////////////////////////////////////////////////////
+
+/// The entries of a row or column vector. Panics for any other shape.
+fn vector_entries<T: IntoMatSpace>(a: &Mat<T>) -> Vec<Elem<T>> {
+    assert!(a.nrows() == 1 || a.ncols() == 1, "matrix is not a vector");
+    a.to_flat()
+}
+
+/// The dot product of two row or column vectors of the same length. The two
+/// need not have the same orientation.
+pub fn dot<T: IntoMatSpace>(a: &Mat<T>, b: &Mat<T>) -> Elem<T> {
+    let (x, y) = (vector_entries(a), vector_entries(b));
+    assert_eq!(x.len(), y.len(), "vectors must have the same length");
+    x.iter().zip(y.iter()).fold(a.base_ring().zero(), |s, (u, v)| {
+        s.op(Additive, &u.op(Multiplicative, v))
+    })
+}
+
+/// The cross product of two vectors of length three, with the shape of `a`.
+pub fn cross<T: IntoMatSpace>(a: &Mat<T>, b: &Mat<T>) -> Mat<T> {
+    let (x, y) = (vector_entries(a), vector_entries(b));
+    assert!(x.len() == 3 && y.len() == 3, "vectors must have length three");
+    let neg = |t: &Elem<T>| <Elem<T> as TwoSidedInverse<Additive>>::two_sided_inverse(t);
+    let minor = |i: usize, j: usize| {
+        let t = y[i].op(Multiplicative, &x[j]);
+        x[i].op(Multiplicative, &y[j]).op(Additive, &neg(&t))
+    };
+    a.parent().from_flat(&[minor(1, 2), minor(2, 0), minor(0, 1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn charpoly_integer() {
+        // det(y*I - A) = y^3 - tr(A)*y^2 + (7 - 2 - 11)*y - det(A), where the
+        // middle coefficient is the sum of the principal 2x2 minors and 
+        // det(A) = 2*(-3 - 8) + 1*(-1) = -23.
+        let a = [[2, -1, 0], [1, 3, 2], [0, 4, -1]];
+        let zz = Integers::init();
+        let m = MatSpace::init(&zz, 3u64, 3u64).from_fn(|i, j| Integer::from(a[i][j]));
+        let f = m.charpoly("y");
+        assert_eq!(f.var(), "y");
+        assert_eq!(
+            f.get_coefficients(), 
+            [23, -6, -4, 1].map(Integer::from).to_vec()
+        );
+    }
+
+    #[test]
+    fn charpoly_intpoly() {
+        // [[x, 1], [1, x]] has characteristic polynomial y^2 - 2x*y + x^2 - 1.
+        let zx = IntPolyRing::init(&Integers::init(), "x");
+        let mut x = zx.zero();
+        x.set_coeff_into(1, 1);
+        let m = MatSpace::init(&zx, 2u64, 2u64)
+            .from_fn(|i, j| if i == j { x.clone() } else { zx.one() });
+        
+        let f = m.charpoly("y");
+        assert_eq!(f.var(), "y");
+        assert_eq!(f.degree(), 2);
+        assert_eq!(f.get_coefficient(0), &x * &x - zx.one());
+        assert_eq!(f.get_coefficient(1), -(&x + &x));
+        assert_eq!(f.get_coefficient(2), zx.one());
+    }
+
+    #[test]
+    fn flat_round_trip() {
+        let zz = Integers::init();
+        let space = MatSpace::init(&zz, 3u64, 4u64);
+        let data: Vec<Integer> = (0..12).map(|k| Integer::from(k * k - 7)).collect();
+        let m = space.from_flat(&data);
+        assert_eq!(m.get_entry(1, 2), Some(Integer::from(29)));
+        assert_eq!(m.get_entry(2, 0), Some(Integer::from(57)));
+        assert_eq!(m.to_flat(), data);
+        assert_eq!(space.from_flat(&m.to_flat()), m);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_flat_wrong_length() {
+        let zz = Integers::init();
+        let data: Vec<Integer> = (0..11).map(Integer::from).collect();
+        MatSpace::init(&zz, 3u64, 4u64).from_flat(&data);
+    }
+
+    #[test]
+    fn dot_and_cross() {
+        let zz = Integers::init();
+        let column = MatSpace::init(&zz, 3u64, 1u64);
+        let row = MatSpace::init(&zz, 1u64, 3u64);
+        let vector = |s: &MatSpace<Integers>, v: [i64; 3]| s.from_flat(&v.map(Integer::from));
+        
+        let a = vector(&column, [1, 2, 3]);
+        let b = vector(&column, [4, -5, 6]);
+        assert_eq!(dot(&a, &b), Integer::from(12));
+        assert_eq!(dot(&a, &vector(&row, [4, -5, 6])), Integer::from(12));
+        
+        let c = cross(&a, &b);
+        assert_eq!(c, vector(&column, [27, 6, -13]));
+        assert!(dot(&c, &a).is_zero());
+        assert!(dot(&c, &b).is_zero());
+        assert_eq!(cross(&b, &a), -c);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_length_mismatch() {
+        let zz = Integers::init();
+        let a = MatSpace::init(&zz, 3u64, 1u64).zero();
+        let b = MatSpace::init(&zz, 2u64, 1u64).zero();
+        dot(&a, &b);
+    }
+
+    #[test]
+    fn structure_predicates() {
+        let zz = Integers::init();
+        let space = MatSpace::init(&zz, 3u64, 3u64);
+        
+        let diag = space.from_fn(|i, j| Integer::from(if i == j { i as i64 + 1 } else { 0 }));
+        assert!(diag.is_diagonal());
+        assert!(diag.is_symmetric());
+        assert!(diag.is_upper_triangular() && diag.is_lower_triangular());
+        
+        let sym = space.from_fn(|i, j| Integer::from((i + j) as i64));
+        assert!(sym.is_symmetric());
+        assert!(!sym.is_diagonal());
+        assert!(!sym.is_upper_triangular() && !sym.is_lower_triangular());
+        
+        let upper = space.from_fn(|i, j| Integer::from(if i <= j { 1 } else { 0 }));
+        assert!(upper.is_upper_triangular());
+        assert!(!upper.is_lower_triangular());
+        assert!(!upper.is_symmetric());
+        
+        let rect = MatSpace::init(&zz, 2u64, 3u64).from_fn(|i, j| Integer::from((i * j) as i64));
+        assert!(!rect.is_symmetric());
+        assert!(MatSpace::init(&zz, 2u64, 3u64).zero().is_diagonal());
+    }
+
+    #[test]
+    fn from_fn_tridiagonal() {
+        let zz = Integers::init();
+        let m = MatSpace::init(&zz, 4u64, 4u64).from_fn(|i, j| {
+            Integer::from(match i as i64 - j as i64 {
+                0 => 2,
+                -1 | 1 => -1,
+                _ => 0,
+            })
+        });
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = if i == j { 2 } else if i.abs_diff(j) == 1 { -1 } else { 0 };
+                assert_eq!(m.get_entry(i, j), Some(Integer::from(expected)));
+            }
+        }
+        // This n x n matrix has determinant n + 1, and for even n that is
+        // also the constant term of the characteristic polynomial.
+        assert_eq!(m.charpoly("y").get_coefficient(0), Integer::from(5));
+    }
+}
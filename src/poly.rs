@@ -23,6 +23,8 @@ use inertia_algebra::ops::NewElement;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
+use crate::GcdDomain;
+
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
@@ -66,6 +68,15 @@ impl<T: IntoPolyRing> PolyRing<T> {
     pub fn into_inner(self) -> InnerPolyRing<T> {
         self.inner
     }
+
+    /// The generator of the polynomial ring, i.e. the variable as a degree
+    /// one polynomial.
+    #[inline]
+    pub fn gen(&self) -> Poly<T> {
+        let mut x = self.zero();
+        x.set_coefficient(1, self.base_ring().one());
+        x
+    }
 }
 
 impl<S, T: IntoPolyRing> NewElement<S> for PolyRing<T>
@@ -248,6 +259,32 @@ impl<T: IntoPolyRing> Poly<T> {
     }
 }
 
+impl<T: IntoPolyRing + GcdDomain> Poly<T> {
+    /// The gcd of the coefficients, or zero for the zero polynomial.
+    pub fn content(&self) -> Elem<T> {
+        let ring = self.base_ring();
+        self.get_coefficients()
+            .iter()
+            .fold(ring.zero(), |g, c| ring.gcd(&g, c))
+    }
+
+    /// The polynomial divided by its content. The zero polynomial is 
+    /// returned unchanged.
+    pub fn primitive_part(&self) -> Poly<T> {
+        let c = self.content();
+        if c.is_zero() {
+            return self.clone();
+        }
+
+        let ring = self.base_ring();
+        let mut res = self.parent().zero();
+        for (i, x) in self.get_coefficients().iter().enumerate() {
+            res.set_coefficient(i, ring.divexact(x, &c));
+        }
+        res
+    }
+}
+
 impl<T: IntoPolyRing> fmt::Display for Poly<T>
 where
     InnerPoly<T>: fmt::Display,
@@ -394,3 +431,52 @@ impl<T: IntoPolyRing> PolynomialRingElement<T> for Poly<T> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn intpoly(s: &str) -> IntPoly {
+        IntPoly::from_pretty_str(s, "x").unwrap()
+    }
+
+    #[test]
+    fn content_over_integers() {
+        let zz = Integers::init();
+        let mut f = PolyRing::init(&zz, "x").zero();
+        f.set_coefficient(0, Integer::from(4));
+        f.set_coefficient(2, Integer::from(-6));
+        assert_eq!(f.content(), Integer::from(2));
+        assert_eq!(
+            f.primitive_part().get_coefficients(), 
+            [2, 0, -3].map(Integer::from).to_vec()
+        );
+    }
+
+    #[test]
+    fn content_over_intpoly() {
+        // (2x + 2)*y^2 + (x^2 - 1)*y in Z[x][y] has content x + 1.
+        let zx = IntPolyRing::init(&Integers::init(), "x");
+        let mut f = PolyRing::init(&zx, "y").zero();
+        f.set_coefficient(2, intpoly("2x + 2"));
+        f.set_coefficient(1, intpoly("x^2 - 1"));
+        assert_eq!(f.content(), intpoly("x + 1"));
+        assert_eq!(
+            f.primitive_part().get_coefficients(), 
+            vec![zx.zero(), intpoly("x - 1"), intpoly("2")]
+        );
+        
+        let zero = PolyRing::init(&zx, "y").zero();
+        assert!(zero.content().is_zero());
+    }
+
+    #[test]
+    fn gen_builds_polynomials() {
+        let zx = PolyRing::init(&Integers::init(), "x");
+        let x = zx.gen();
+        assert_eq!(x.degree(), 1);
+        assert_eq!(x.get_coefficients(), [0, 1].map(Integer::from).to_vec());
+        
+        let f = &x * &x + Integer::from(1);
+        assert_eq!(f.get_coefficients(), [1, 0, 1].map(Integer::from).to_vec());
+    }
+}
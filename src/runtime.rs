@@ -0,0 +1,61 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+//! Configuration of the underlying FLINT runtime.
+//!
+//! FLINT keeps its thread count and various caches in thread-local storage,
+//! so these functions only affect the calling thread.
+
+use std::os::raw::c_int;
+use inertia_core::flint_sys::flint;
+
+/// Set the number of threads FLINT may use for multithreaded algorithms.
+#[inline]
+pub fn set_num_threads(n: usize) {
+    assert!(n > 0, "number of threads must be positive");
+    // SAFETY: FLINT only stores the count in thread-local state.
+    unsafe { flint::flint_set_num_threads(n as c_int); }
+}
+
+/// The number of threads FLINT may use for multithreaded algorithms.
+#[inline]
+pub fn get_num_threads() -> usize {
+    // SAFETY: reads thread-local state with no preconditions.
+    unsafe { flint::flint_get_num_threads() as usize }
+}
+
+/// Release the memory FLINT has cached for the calling thread.
+#[inline]
+pub fn cleanup() {
+    // SAFETY: FLINT reallocates anything it needs after a cleanup.
+    unsafe { flint::flint_cleanup(); }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn num_threads_round_trip() {
+        let old = get_num_threads();
+        set_num_threads(3);
+        assert_eq!(get_num_threads(), 3);
+        set_num_threads(old);
+        assert_eq!(get_num_threads(), old);
+    }
+}
@@ -0,0 +1,132 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+
+//! Convenience methods on top of the [inertia_algebra] traits.
+
+use inertia_algebra::*;
+
+// The bound on the parent is an associated type bound on the supertrait so
+// that it is implied wherever `T: RingElementExt` is assumed.
+pub trait RingElementExt: Element<Parent: Ring + Parent<Element = Self>> + Sized {
+    /// The additive identity in the same parent as `self`.
+    #[inline]
+    fn zero_like(&self) -> Self {
+        self.parent().zero()
+    }
+    
+    /// The multiplicative identity in the same parent as `self`.
+    #[inline]
+    fn one_like(&self) -> Self {
+        self.parent().one()
+    }
+}
+
+impl<T> RingElementExt for T
+where
+    T: Element,
+    T::Parent: Ring + Parent<Element = T>
+{}
+
+pub trait PolynomialRingElementExt<T: Ring>: PolynomialRingElement<T> {
+    /// The degree of the polynomial, or `None` for the zero polynomial.
+    #[inline]
+    fn degree_opt(&self) -> Option<usize> {
+        let d = self.degree();
+        if d < 0 {
+            None
+        } else {
+            Some(d as usize)
+        }
+    }
+
+    /// The coefficient of the highest degree term, or zero for the zero 
+    /// polynomial.
+    #[inline]
+    fn leading_coeff(&self) -> Elem<T> {
+        match self.degree_opt() {
+            Some(d) => self.get_coefficient(d),
+            None => self.base_ring().zero()
+        }
+    }
+
+    /// The coefficient of the lowest degree nonzero term, or zero for the zero
+    /// polynomial.
+    #[inline]
+    fn trailing_coeff(&self) -> Elem<T> {
+        for i in 0..self.len() {
+            let c = self.get_coefficient(i);
+            if !c.is_zero() {
+                return c;
+            }
+        }
+        self.base_ring().zero()
+    }
+}
+
+impl<T: Ring, P: PolynomialRingElement<T>> PolynomialRingElementExt<T> for P {}
+
+/// A ring with a gcd, used for content and primitive part computations.
+pub trait GcdDomain: Ring {
+    /// A greatest common divisor of `a` and `b`.
+    fn gcd(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self>;
+    
+    /// Exact division of `a` by `b`, where `b` is assumed to divide `a`.
+    fn divexact(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn sum_or_zero<T: RingElementExt>(xs: &[T], like: &T) -> T {
+        xs.iter().fold(like.zero_like(), |acc, x| acc.op(Additive, x))
+    }
+
+    #[test]
+    fn zero_like_one_like_integer() {
+        let a = Integer::from(7);
+        assert_eq!(a.zero_like(), Integer::zero());
+        assert_eq!(a.one_like(), Integer::one());
+        
+        let xs = [Integer::from(2), Integer::from(3)];
+        assert_eq!(sum_or_zero(&xs, &a), Integer::from(5));
+        assert_eq!(sum_or_zero(&[], &a), Integer::zero());
+    }
+
+    fn poly(s: &str) -> IntPoly {
+        IntPoly::from_pretty_str(s, "x").unwrap()
+    }
+
+    #[test]
+    fn degree_opt() {
+        assert_eq!(poly("3x^2 + 1").degree_opt(), Some(2));
+        assert_eq!(IntPoly::one("x").degree_opt(), Some(0));
+        assert_eq!(IntPoly::zero("x").degree_opt(), None);
+    }
+
+    #[test]
+    fn leading_trailing_coeff() {
+        let f = poly("-2x^3 + 5x");
+        assert_eq!(f.leading_coeff(), Integer::from(-2));
+        assert_eq!(f.trailing_coeff(), Integer::from(5));
+        
+        let zero = IntPoly::zero("x");
+        assert!(zero.leading_coeff().is_zero());
+        assert!(zero.trailing_coeff().is_zero());
+    }
+}